@@ -1,29 +1,235 @@
-// TODO maybe pull from https://github.com/rust-lang/rust-www/blob/master/_data/team.yml instead
-
 use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io;
+use std::mem;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
 
 use diesel::prelude::*;
 use toml;
+use json5;
 use arrayvec::ArrayVec;
+use arc_swap::ArcSwap;
+use signal_hook::iterator::Signals;
+use reqwest;
+use regex::Regex;
 
 use super::DB_POOL;
 use domain::github::{GitHubUser};
 use github::models::Reaction;
 use error::*;
 
+/// Environment variable pointing at the config file to load.
+const CONFIG_PATH_VAR: &str = "RFCBOT_CONFIG_PATH";
+
+/// Base URL of the canonical rust-lang/team roster API.
+const RUST_LANG_TEAM_API: &str = "https://team-api.infra.rust-lang.org/v1";
+
+/// How often `source = "rust-lang/team"` teams get refreshed from upstream.
+const TEAM_SYNC_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
 //==============================================================================
 // Public API
 //==============================================================================
 
 lazy_static! {
-    pub static ref SETUP: RfcbotConfig = read_rfcbot_cfg_validated();
+    pub static ref SETUP: ArcSwap<RfcbotConfig> =
+        ArcSwap::from_pointee(read_rfcbot_cfg_validated(&config_path()));
+}
+
+/// Reload the configuration on `SIGHUP`.
+pub fn watch_for_reload() -> DashResult<()> {
+    let mut signals = Signals::new(&[libc::SIGHUP])?;
+
+    thread::spawn(move || {
+        for _ in signals.forever() {
+            info!("received SIGHUP, reloading configuration");
+            reload();
+        }
+    });
+
+    Ok(())
+}
+
+/// Reload the configuration, logging and keeping the old one on failure.
+pub fn reload() {
+    let path = config_path();
+    let mut load_error = None;
+
+    SETUP.rcu(|current| match reloaded_config(&path, current) {
+        Ok(cfg) => {
+            load_error = None;
+            Arc::new(cfg)
+        }
+        Err(why) => {
+            load_error = Some(why);
+            Arc::clone(current)
+        }
+    });
+
+    match load_error {
+        None => info!("reloaded configuration from {}", path.display()),
+        Some(why) => error!("not reloading configuration from {}, it's broken: {:?}",
+                             path.display(), why),
+    }
+}
+
+/// Parse and validate `path`, carrying over `current`'s synced team members.
+fn reloaded_config(path: &Path, current: &RfcbotConfig) -> DashResult<RfcbotConfig> {
+    let mut cfg = read_rfcbot_cfg_from_path(path)?;
+
+    for (label, team) in cfg.teams.iter_mut() {
+        if team.source == TeamSource::RustLangTeam {
+            if let Some(previous) = current.teams.get(label) {
+                team.members = previous.members.clone();
+            }
+        }
+    }
+
+    for team in cfg.teams.values() {
+        team.validate()?;
+    }
+
+    Ok(cfg)
+}
+
+fn config_path() -> PathBuf {
+    env::var_os(CONFIG_PATH_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("rfcbot.toml"))
+}
+
+/// Periodically refresh every `rust-lang/team`-sourced team from upstream.
+pub fn watch_for_team_sync() {
+    thread::spawn(|| loop {
+        sync_teams_from_rust_lang_team();
+        thread::sleep(TEAM_SYNC_INTERVAL);
+    });
+}
+
+fn sync_teams_from_rust_lang_team() {
+    let labels: Vec<TeamLabel> = SETUP.load().teams().filter(|(_, team)| team.source == TeamSource::RustLangTeam)
+        .map(|(label, _)| label.clone())
+        .collect();
+
+    let mut fetched = BTreeMap::new();
+    for label in labels {
+        match fetch_rust_lang_team(&label.0) {
+            Ok(members) => { fetched.insert(label, members); }
+            Err(why) => error!("couldn't sync team {:?} from rust-lang/team: {:?}", label, why),
+        }
+    }
+
+    if fetched.is_empty() {
+        return;
+    }
+
+    // `rcu` re-runs this closure against whatever `SETUP` holds each time,
+    // so a racing `reload()` composes with this sync instead of one
+    // clobbering the other.
+    SETUP.rcu(|current| {
+        let mut cfg = (**current).clone();
+
+        for (label, members) in &fetched {
+            let team = match cfg.teams.get_mut(label) {
+                Some(team) => team,
+                None => continue,
+            };
+
+            let previous_members = mem::replace(&mut team.members, members.clone());
+            if let Err(why) = team.validate() {
+                error!("keeping last-known-good members for team {:?}, sync failed to validate: {:?}",
+                       label, why);
+                team.members = previous_members;
+            }
+        }
+
+        Arc::new(cfg)
+    });
+
+    info!("synced teams from rust-lang/team");
+}
+
+fn fetch_rust_lang_team(name: &str) -> DashResult<Vec<String>> {
+    let url = format!("{}/teams/{}.json", RUST_LANG_TEAM_API, name);
+    let resp: RustLangTeamResponse = reqwest::blocking::get(&url)?.json()?;
+    Ok(resp.members.into_iter().map(|member| member.github).collect())
 }
 
 #[derive(Debug, Deserialize)]
+struct RustLangTeamResponse {
+    members: Vec<RustLangTeamMember>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RustLangTeamMember {
+    github: String,
+}
+
+/// A canonical bot command, as recognized regardless of which literal text
+/// or alias triggered it.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Command {
+    FcpMerge,
+    FcpClose,
+    FcpPostpone,
+    FcpCancel,
+    Concern,
+    Resolve,
+}
+
+impl Command {
+    fn config_key(self) -> &'static str {
+        match self {
+            Command::FcpMerge => "fcp merge",
+            Command::FcpClose => "fcp close",
+            Command::FcpPostpone => "fcp postpone",
+            Command::FcpCancel => "fcp cancel",
+            Command::Concern => "concern",
+            Command::Resolve => "resolve",
+        }
+    }
+
+    fn from_config_key(key: &str) -> Option<Command> {
+        match key {
+            "fcp merge" => Some(Command::FcpMerge),
+            "fcp close" => Some(Command::FcpClose),
+            "fcp postpone" => Some(Command::FcpPostpone),
+            "fcp cancel" => Some(Command::FcpCancel),
+            "concern" => Some(Command::Concern),
+            "resolve" => Some(Command::Resolve),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
 pub struct RfcbotConfig {
     prohibited_reactions: BTreeMap<String, ReactionBehaviorConfig>,
     fcp_behaviors: BTreeMap<String, FcpBehavior>,
     teams: BTreeMap<TeamLabel, Team>,
+    #[serde(default)]
+    messages: BTreeMap<String, BTreeMap<String, String>>,
+
+    /// Per-repo command access control: repo -> command (e.g. `"fcp merge"`)
+    /// -> the team labels and/or individual logins allowed to issue it. A
+    /// repo/command pair with no entry here is open to anyone, so this only
+    /// needs to be set for commands worth locking down.
+    #[serde(default)]
+    access_control: BTreeMap<String, BTreeMap<String, Vec<String>>>,
+
+    /// Extra regex patterns that resolve to a canonical command, letting
+    /// maintainers register extra phrasing alongside the built-in vocabulary
+    /// recognized elsewhere. This one table applies to every repo.
+    #[serde(default)]
+    aliases: BTreeMap<String, String>,
+
+    /// `aliases` compiled to `Regex`es once, at load time.
+    #[serde(skip)]
+    compiled_aliases: Vec<(Regex, Command)>,
 }
 
 impl RfcbotConfig {
@@ -60,6 +266,108 @@ impl RfcbotConfig {
             .map(|rb| rb.comment.prohibited_reactions())
             .unwrap_or_default()
     }
+
+    /// Returns the `key` message template for `repo`, falling back to the
+    /// compiled-in default. The result is a template -- pass it to `render`.
+    pub fn message(&self, repo: &str, key: &str) -> &str {
+        self.messages.get(repo)
+            .and_then(|overrides| overrides.get(key))
+            .map(String::as_str)
+            .unwrap_or_else(|| default_message(key))
+    }
+
+    /// Is `login` allowed to issue `command` in `repo`?
+    ///
+    /// A repo/command pair with no `access_control` entry is open to
+    /// everyone, matching rfcbot's historical behavior of treating all team
+    /// members the same. Once a repo sets an entry, `login` must either be
+    /// listed directly or belong to one of the listed teams.
+    pub fn is_allowed(&self, repo: &str, login: &str, command: Command) -> bool {
+        let allowed = match self.access_control.get(repo)
+            .and_then(|commands| commands.get(command.config_key()))
+        {
+            Some(allowed) => allowed,
+            None => return true,
+        };
+
+        allowed.iter().any(|entry| {
+            entry == login
+                || self.teams.get(&TeamLabel(entry.clone()))
+                    .map(|team| team.member_logins().any(|member| member == login))
+                    .unwrap_or_default()
+        })
+    }
+
+    /// Resolve `input` against the configured `aliases`, returning the
+    /// canonical command it maps to, if any.
+    pub fn resolve_alias(&self, input: &str) -> Option<Command> {
+        self.compiled_aliases.iter()
+            .find(|(re, _)| re.is_match(input))
+            .map(|&(_, command)| command)
+    }
+
+    /// Compile `aliases` into `compiled_aliases`. Must be called once after
+    /// deserializing, since `Regex` doesn't implement `Deserialize`.
+    fn compile_aliases(&mut self) {
+        self.compiled_aliases = self.aliases.iter().filter_map(|(pattern, command)| {
+            let command = match Command::from_config_key(command) {
+                Some(command) => command,
+                None => {
+                    error!("alias {:?} maps to unknown command {:?}", pattern, command);
+                    return None;
+                }
+            };
+
+            match Regex::new(pattern) {
+                Ok(re) => Some((re, command)),
+                Err(why) => {
+                    error!("invalid alias regex {:?}: {:?}", pattern, why);
+                    None
+                }
+            }
+        }).collect();
+    }
+
+    /// An `RfcbotConfig` with every table empty, used as the accumulator
+    /// when merging a directory of config fragments together.
+    fn empty() -> RfcbotConfig {
+        RfcbotConfig {
+            prohibited_reactions: BTreeMap::new(),
+            fcp_behaviors: BTreeMap::new(),
+            teams: BTreeMap::new(),
+            messages: BTreeMap::new(),
+            access_control: BTreeMap::new(),
+            aliases: BTreeMap::new(),
+            compiled_aliases: Vec::new(),
+        }
+    }
+}
+
+/// Substitute every `{name}` placeholder in `template` with its matching
+/// value from `params`. Placeholders with no matching entry are left as-is.
+pub fn render(template: &str, params: &[(&str, &str)]) -> String {
+    let mut rendered = template.to_string();
+    for (name, value) in params {
+        rendered = rendered.replace(&format!("{{{}}}", name), value);
+    }
+    rendered
+}
+
+/// Compiled-in fallback templates, used whenever a repo doesn't override
+/// `key` in its `[messages."<repo>"]` table.
+fn default_message(key: &str) -> &'static str {
+    match key {
+        "fcp_proposed" =>
+            "Team member @{proposer} has proposed to merge this. The next step is review by \
+             the rest of the tagged teams:",
+        "concern_raised" =>
+            "@{author} has raised a concern that must be addressed before this can enter its \
+             final comment period.",
+        "fcp_stale" =>
+            "🔔 This is now entering its final comment period, as per the review above. \
+             {team}, please check your boxes!",
+        _ => "",
+    }
 }
 
 #[derive(Copy, Clone, Debug, Default, Deserialize)]
@@ -104,7 +412,7 @@ impl ProhibitedReactions {
     }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize)]
 struct FcpBehavior {
     #[serde(default)]
     close: bool,
@@ -112,7 +420,7 @@ struct FcpBehavior {
     postpone: bool,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize)]
 pub struct Team {
     // FIXME(2018-05-16):
     // The two following first fields are not used anymore.
@@ -121,7 +429,14 @@ pub struct Team {
     //name: String,
     //ping: String,
 
+    #[serde(default)]
     members: Vec<String>,
+
+    /// Where `members` comes from. Defaults to the literal list above;
+    /// `source = "rust-lang/team"` instead keeps it synced at runtime from
+    /// the upstream rust-lang/team roster, looked up by this team's label.
+    #[serde(default)]
+    source: TeamSource,
 }
 
 impl Team {
@@ -130,7 +445,21 @@ impl Team {
     }
 }
 
-#[derive(Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum TeamSource {
+    File,
+    #[serde(rename = "rust-lang/team")]
+    RustLangTeam,
+}
+
+impl Default for TeamSource {
+    fn default() -> Self {
+        TeamSource::File
+    }
+}
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize)]
 #[serde(transparent)]
 pub struct TeamLabel(pub String);
 
@@ -138,9 +467,11 @@ pub struct TeamLabel(pub String);
 // Implementation details
 //==============================================================================
 
-/// Read the validated `rfcbot.toml` configuration file.
-fn read_rfcbot_cfg_validated() -> RfcbotConfig {
-    let cfg = read_rfcbot_cfg();
+/// Read and validate the configuration at `path`, panicking on any failure.
+/// Only meant for the initial load at startup -- see `reload` afterwards.
+fn read_rfcbot_cfg_validated(path: &Path) -> RfcbotConfig {
+    let cfg = read_rfcbot_cfg_from_path(path)
+        .unwrap_or_else(|why| panic!("couldn't load configuration from {}: {:?}", path.display(), why));
 
     cfg.teams.values().for_each(|team|
         team.validate()
@@ -151,14 +482,111 @@ if you're running this for tests, make sure you've pulled github users from prod
     cfg
 }
 
-/// Read the unprocessed `rfcbot.toml` configuration file.
-fn read_rfcbot_cfg() -> RfcbotConfig {
-    read_rfcbot_cfg_from(
-        include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/rfcbot.toml")))
+/// Read the configuration at `path` (a single file, or a directory of
+/// merged `*.toml`/`*.json5` fragments), returning an error instead of
+/// panicking on failure.
+fn read_rfcbot_cfg_from_path(path: &Path) -> DashResult<RfcbotConfig> {
+    let mut cfg = if path.is_dir() {
+        read_rfcbot_cfg_dir(path)?
+    } else {
+        let input = fs::read_to_string(path)?;
+        parse_single_file(path, &input)?
+    };
+
+    cfg.compile_aliases();
+    Ok(cfg)
+}
+
+/// Deserialize a single, self-contained config file, keeping
+/// `prohibited_reactions`/`fcp_behaviors`/`teams` required.
+fn parse_single_file(path: &Path, input: &str) -> DashResult<RfcbotConfig> {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("json5") => json5::from_str(input)
+            .map_err(|why| io::Error::new(io::ErrorKind::InvalidData, why.to_string()).into()),
+        _ => Ok(toml::from_str(input)?),
+    }
+}
+
+fn read_rfcbot_cfg_dir(dir: &Path) -> DashResult<RfcbotConfig> {
+    let mut fragments = Vec::new();
+    collect_fragments(dir, &mut fragments)?;
+    fragments.sort();
+
+    let mut cfg = RfcbotConfig::empty();
+    for path in &fragments {
+        let input = fs::read_to_string(path)?;
+        parse_fragment(path, &input)?.merge_into(&mut cfg, path)?;
+    }
+
+    Ok(cfg)
+}
+
+fn collect_fragments(dir: &Path, found: &mut Vec<PathBuf>) -> DashResult<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_fragments(&path, found)?;
+        } else if is_fragment_file(&path) {
+            found.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+fn is_fragment_file(path: &Path) -> bool {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("toml") | Some("json5") => true,
+        _ => false,
+    }
+}
+
+/// Deserialize one fragment of a directory-based config. Every table is
+/// optional here, since a fragment only needs to supply what it overrides.
+fn parse_fragment(path: &Path, input: &str) -> DashResult<PartialRfcbotConfig> {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("json5") => json5::from_str(input)
+            .map_err(|why| io::Error::new(io::ErrorKind::InvalidData, why.to_string()).into()),
+        _ => Ok(toml::from_str(input)?),
+    }
+}
+
+/// The same tables as `RfcbotConfig`, but all defaulting to empty.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct PartialRfcbotConfig {
+    prohibited_reactions: BTreeMap<String, ReactionBehaviorConfig>,
+    fcp_behaviors: BTreeMap<String, FcpBehavior>,
+    teams: BTreeMap<TeamLabel, Team>,
+    messages: BTreeMap<String, BTreeMap<String, String>>,
+    access_control: BTreeMap<String, BTreeMap<String, Vec<String>>>,
+    aliases: BTreeMap<String, String>,
 }
 
-fn read_rfcbot_cfg_from(input: &str) -> RfcbotConfig {
-    toml::from_str(input).expect("couldn't parse rfcbot.toml!")
+impl PartialRfcbotConfig {
+    fn merge_into(self, cfg: &mut RfcbotConfig, source: &Path) -> DashResult<()> {
+        cfg.prohibited_reactions.extend(self.prohibited_reactions);
+        cfg.fcp_behaviors.extend(self.fcp_behaviors);
+        cfg.aliases.extend(self.aliases);
+
+        for (repo, overrides) in self.messages {
+            cfg.messages.entry(repo).or_insert_with(BTreeMap::new).extend(overrides);
+        }
+        for (repo, commands) in self.access_control {
+            cfg.access_control.entry(repo).or_insert_with(BTreeMap::new).extend(commands);
+        }
+
+        for (label, team) in self.teams {
+            if cfg.teams.insert(label.clone(), team).is_some() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("duplicate team {:?} found while merging {}", label, source.display()),
+                ).into());
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl Team {
@@ -240,7 +668,7 @@ members = [
   "theflash"
 ]
 "#;
-        let cfg = read_rfcbot_cfg_from(test);
+        let cfg = parse_single_file(Path::new("rfcbot.toml"), test).unwrap();
 
         // Labels are correct:
         assert_eq!(cfg.team_labels().map(|tl| tl.0.clone()).collect::<Vec<_>>(),
@@ -289,16 +717,130 @@ members = [
         assert!(cfg.prohibited_comment_reactions("random").is_empty());
     }
 
+    #[test]
+    fn message_falls_back_to_default_and_renders_placeholders() {
+        let cfg = parse_single_file(Path::new("rfcbot.toml"), r#"
+[prohibited_reactions]
+[fcp_behaviors]
+[teams]
+
+[messages."rust-lang/rust"]
+fcp_proposed = "Custom: @{proposer} proposed this for {team}."
+"#).unwrap();
+
+        // A repo with an override for this key gets it back verbatim:
+        assert_eq!(cfg.message("rust-lang/rust", "fcp_proposed"),
+                   "Custom: @{proposer} proposed this for {team}.");
+
+        // A repo with no override falls back to the compiled-in default:
+        assert_eq!(cfg.message("other/repo", "fcp_proposed"), default_message("fcp_proposed"));
+
+        // An unknown key with no override is an empty template, not a panic:
+        assert_eq!(cfg.message("other/repo", "no-such-key"), "");
+
+        // Placeholder substitution:
+        assert_eq!(
+            render("@{proposer} says hi to {team}", &[("proposer", "alice"), ("team", "core")]),
+            "@alice says hi to core");
+
+        // Placeholders with no matching param are left as-is:
+        assert_eq!(render("@{unknown} stays put", &[("proposer", "alice")]), "@{unknown} stays put");
+    }
+
+    #[test]
+    fn access_control_and_aliases() {
+        let cfg = parse_single_file(Path::new("rfcbot.toml"), r#"
+[prohibited_reactions]
+[fcp_behaviors]
+
+[teams]
+[teams.core]
+members = ["alice", "bob"]
+
+[access_control."rust-lang/rust"]
+"fcp merge" = ["core"]
+"concern" = ["eve"]
+
+[aliases]
+"^r\\+$" = "fcp merge"
+"^concern\\b" = "concern"
+"#).unwrap();
+
+        // A member of the allowed team can issue the locked-down command;
+        // a non-member can't:
+        assert!(cfg.is_allowed("rust-lang/rust", "alice", Command::FcpMerge));
+        assert!(!cfg.is_allowed("rust-lang/rust", "mallory", Command::FcpMerge));
+
+        // A login listed directly (not via a team) is allowed too:
+        assert!(cfg.is_allowed("rust-lang/rust", "eve", Command::Concern));
+        assert!(!cfg.is_allowed("rust-lang/rust", "alice", Command::Concern));
+
+        // A repo/command pair with no access_control entry is open to anyone:
+        assert!(cfg.is_allowed("rust-lang/rust", "mallory", Command::Resolve));
+        assert!(cfg.is_allowed("other/repo", "mallory", Command::FcpMerge));
+
+        // Aliases resolve to their canonical command; unmatched text doesn't:
+        assert_eq!(cfg.resolve_alias("r+"), Some(Command::FcpMerge));
+        assert_eq!(cfg.resolve_alias("concern: this seems bad"), Some(Command::Concern));
+        assert_eq!(cfg.resolve_alias("unrelated text"), None);
+    }
+
+    #[test]
+    fn directory_config_merges_fragments_and_rejects_duplicate_teams() {
+        use std::process;
+
+        let dir = env::temp_dir().join(format!("rfcbot-test-{}-merge", process::id()));
+        fs::create_dir_all(dir.join("teams")).unwrap();
+
+        fs::write(dir.join("main.toml"), r#"
+[prohibited_reactions]
+[fcp_behaviors]
+
+[fcp_behaviors."rust-lang/alpha"]
+close = true
+"#).unwrap();
+
+        fs::write(dir.join("teams").join("avengers.toml"), r#"
+[teams.avengers]
+members = ["hulk", "thor"]
+"#).unwrap();
+
+        fs::write(dir.join("teams").join("justice-league.toml"), r#"
+[teams.justice-league]
+members = ["superman"]
+"#).unwrap();
+
+        let cfg = read_rfcbot_cfg_from_path(&dir).unwrap();
+
+        // fcp_behaviors from the top-level fragment made it through:
+        assert!(cfg.should_ffcp_auto_close("rust-lang/alpha"));
+
+        // teams from both per-team fragments got merged into one map:
+        let mut labels = cfg.team_labels().map(|tl| tl.0.clone()).collect::<Vec<_>>();
+        labels.sort();
+        assert_eq!(labels, vec!["avengers", "justice-league"]);
+
+        // A second fragment claiming an already-used team label is a clear
+        // error, not a silent overwrite:
+        fs::write(dir.join("teams").join("avengers-again.toml"), r#"
+[teams.avengers]
+members = ["ironman"]
+"#).unwrap();
+        assert!(read_rfcbot_cfg_from_path(&dir).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
     #[test]
     fn cfg_file_wellformed() {
         // Just parse it and ensure that we get no panics for now!
         // This is a crap test; but, better than nothing.
-        let _ = read_rfcbot_cfg();
+        let _ = read_rfcbot_cfg_from_path(&config_path()).unwrap();
     }
 
     #[test]
     fn team_members_exist() {
-        for (label, _) in SETUP.teams.iter() {
+        for (label, _) in SETUP.load().teams.iter() {
             println!("found team {:?}", label);
         }
     }